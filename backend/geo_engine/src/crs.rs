@@ -0,0 +1,50 @@
+//! Coordinate reference system handling.
+//!
+//! Importers assume incoming coordinates are already WGS84 (EPSG:4326) lon/lat
+//! pairs. This module reprojects coordinates declared in another SRID into
+//! WGS84 before they reach the rest of the pipeline, so projected sources
+//! (e.g. Web Mercator exports) don't silently produce garbage geometry.
+
+const WGS84_SRID: i32 = 4326;
+const WEB_MERCATOR_SRID: i32 = 3857;
+const EARTH_RADIUS_M: f64 = 6378137.0;
+
+/// Reproject a single `(x, y)` coordinate from `source_srid` into WGS84
+/// lon/lat. A no-op when `source_srid` is already 4326.
+pub fn to_wgs84(x: f64, y: f64, source_srid: i32) -> Result<(f64, f64), String> {
+    match source_srid {
+        WGS84_SRID => Ok((x, y)),
+        WEB_MERCATOR_SRID => Ok(web_mercator_to_wgs84(x, y)),
+        other => reproject_with_proj(x, y, other),
+    }
+}
+
+/// EPSG:3857 (Web Mercator) to EPSG:4326 is a closed-form transform, so it's
+/// handled directly instead of round-tripping through a `proj` transformer.
+fn web_mercator_to_wgs84(x: f64, y: f64) -> (f64, f64) {
+    let lon = x / EARTH_RADIUS_M * 180.0 / std::f64::consts::PI;
+    let lat = (2.0 * (y / EARTH_RADIUS_M).exp().atan() - std::f64::consts::FRAC_PI_2) * 180.0
+        / std::f64::consts::PI;
+    (lon, lat)
+}
+
+#[cfg(feature = "proj")]
+fn reproject_with_proj(x: f64, y: f64, source_srid: i32) -> Result<(f64, f64), String> {
+    use proj::Proj;
+
+    let transformer = Proj::new_known_crs(&format!("EPSG:{}", source_srid), "EPSG:4326", None)
+        .map_err(|e| format!("Unsupported source SRID {}: {}", source_srid, e))?;
+
+    transformer
+        .convert((x, y))
+        .map_err(|e| format!("Failed to reproject from EPSG:{}: {}", source_srid, e))
+}
+
+#[cfg(not(feature = "proj"))]
+fn reproject_with_proj(_x: f64, _y: f64, source_srid: i32) -> Result<(f64, f64), String> {
+    Err(format!(
+        "Unsupported source SRID {} (EPSG:4326 and EPSG:3857 are built in; enable the `proj` \
+         feature for arbitrary CRS support)",
+        source_srid
+    ))
+}
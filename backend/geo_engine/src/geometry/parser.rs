@@ -1,6 +1,10 @@
-use geojson::{Feature, GeoJson};
+use crate::crs;
+use geojson::{Feature, GeoJson, PointType, Value};
 use pyo3::prelude::*;
 use std::fs;
+use std::io::{BufReader, Read};
+
+const WGS84_SRID: i32 = 4326;
 
 #[pyclass]
 #[derive(Debug, Clone)]
@@ -14,14 +18,19 @@ pub struct ParsedFeature {
 }
 
 #[pyfunction]
-pub fn parse_geojson_file(path: &str) -> PyResult<Vec<ParsedFeature>> {
+#[pyo3(signature = (path, source_srid=None))]
+pub fn parse_geojson_file(path: &str, source_srid: Option<i32>) -> PyResult<Vec<ParsedFeature>> {
     let content = fs::read_to_string(path)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
-    parse_geojson_string(&content)
+    parse_geojson_string(&content, source_srid)
 }
 
 #[pyfunction]
-pub fn parse_geojson_string(content: &str) -> PyResult<Vec<ParsedFeature>> {
+#[pyo3(signature = (content, source_srid=None))]
+pub fn parse_geojson_string(
+    content: &str,
+    source_srid: Option<i32>,
+) -> PyResult<Vec<ParsedFeature>> {
     let geojson: GeoJson = content
         .parse()
         .map_err(|e: geojson::Error| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
@@ -38,32 +47,375 @@ pub fn parse_geojson_string(content: &str) -> PyResult<Vec<ParsedFeature>> {
         }],
     };
 
+    let srid = source_srid.unwrap_or(WGS84_SRID);
     let mut parsed = Vec::with_capacity(features.len());
 
     for feature in features {
-        if let Some(geom) = feature.geometry {
-            let geom_type = match &geom.value {
-                geojson::Value::Point(_) => "Point",
-                geojson::Value::MultiPoint(_) => "MultiPoint",
-                geojson::Value::LineString(_) => "LineString",
-                geojson::Value::MultiLineString(_) => "MultiLineString",
-                geojson::Value::Polygon(_) => "Polygon",
-                geojson::Value::MultiPolygon(_) => "MultiPolygon",
-                geojson::Value::GeometryCollection(_) => "GeometryCollection",
+        if let Some(p) = feature_to_parsed(feature, srid)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?
+        {
+            parsed.push(p);
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Extract the `ParsedFeature` fields (geometry, properties, geom type) from
+/// a single GeoJSON feature, reprojecting into WGS84 if `source_srid` isn't
+/// already 4326. Returns `None` for a feature with no geometry.
+pub(crate) fn feature_to_parsed(
+    feature: Feature,
+    source_srid: i32,
+) -> Result<Option<ParsedFeature>, String> {
+    let Some(mut geom) = feature.geometry else {
+        return Ok(None);
+    };
+
+    if source_srid != WGS84_SRID {
+        geom.value = reproject_value(geom.value, source_srid)?;
+    }
+
+    let geom_type = match &geom.value {
+        Value::Point(_) => "Point",
+        Value::MultiPoint(_) => "MultiPoint",
+        Value::LineString(_) => "LineString",
+        Value::MultiLineString(_) => "MultiLineString",
+        Value::Polygon(_) => "Polygon",
+        Value::MultiPolygon(_) => "MultiPolygon",
+        Value::GeometryCollection(_) => "GeometryCollection",
+    };
+
+    let props = feature
+        .properties
+        .map(|p| serde_json::to_string(&p).unwrap_or_default())
+        .unwrap_or_else(|| "{}".to_string());
+
+    Ok(Some(ParsedFeature {
+        geometry: geom.to_string(),
+        properties: props,
+        geom_type: geom_type.to_string(),
+    }))
+}
+
+/// A batch of streamed features, mirroring `CsvPointBatch`'s chunking API.
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct FeatureBatch {
+    #[pyo3(get)]
+    pub features: Vec<ParsedFeature>,
+}
+
+/// Incrementally stream features out of a GeoJSON `FeatureCollection` too
+/// large to hold in memory as a `String` and a `Vec<ParsedFeature>`, the way
+/// [`parse_geojson_file`] does. Scans the top-level `features` array one
+/// object at a time off a `BufReader` and yields fixed-size batches.
+#[pyfunction]
+#[pyo3(signature = (path, chunk_size, source_srid=None))]
+pub fn stream_geojson_features(
+    path: &str,
+    chunk_size: usize,
+    source_srid: Option<i32>,
+) -> PyResult<Vec<FeatureBatch>> {
+    let file = fs::File::open(path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
+    let srid = source_srid.unwrap_or(WGS84_SRID);
+
+    let mut scanner = FeatureArrayScanner::new(BufReader::new(file));
+    let mut batches = Vec::new();
+    let mut current = Vec::with_capacity(chunk_size.max(1));
+
+    while let Some(raw) = scanner
+        .next_feature()
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?
+    {
+        let feature: Feature = serde_json::from_str(&raw)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+
+        if let Some(parsed) = feature_to_parsed(feature, srid)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?
+        {
+            current.push(parsed);
+            if current.len() == chunk_size.max(1) {
+                batches.push(FeatureBatch {
+                    features: std::mem::take(&mut current),
+                });
+            }
+        }
+    }
+
+    if !current.is_empty() {
+        batches.push(FeatureBatch { features: current });
+    }
+
+    Ok(batches)
+}
+
+/// Scans a GeoJSON `FeatureCollection`'s `features` array from a `Read`,
+/// yielding the raw JSON text of one feature object at a time so the
+/// reader never holds more than a single feature (plus the caller's
+/// current batch) in memory.
+struct FeatureArrayScanner<R: Read> {
+    reader: R,
+    started: bool,
+    finished: bool,
+}
+
+impl<R: Read> FeatureArrayScanner<R> {
+    fn new(reader: R) -> Self {
+        FeatureArrayScanner {
+            reader,
+            started: false,
+            finished: false,
+        }
+    }
+
+    fn next_byte(&mut self) -> Result<Option<u8>, String> {
+        let mut buf = [0u8; 1];
+        loop {
+            return match self.reader.read(&mut buf) {
+                Ok(0) => Ok(None),
+                Ok(_) => Ok(Some(buf[0])),
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => Err(e.to_string()),
             };
+        }
+    }
+
+    /// Advance past everything up to and including the opening `[` of the
+    /// top-level `"features"` array.
+    ///
+    /// This is a plain byte-substring search for `"features"` (quotes
+    /// included), not a string-aware scan: the needle already encodes the
+    /// surrounding quotes, so gating the match on "are we inside a JSON
+    /// string" would mean the opening quote flips that state exactly when
+    /// the rest of the needle needs it to stay matching, and the search
+    /// could never succeed. A match is only accepted as the real key,
+    /// though, if nothing but whitespace and a single `:` separate it from
+    /// a following `[` — otherwise the literal substring `"features"`
+    /// occurred as some other key or string value (e.g. a nested
+    /// `"properties":{"features":"river delta"}`) and the search resumes
+    /// looking for a later occurrence instead of latching onto an
+    /// unrelated `[` further down the stream.
+    fn seek_to_features_array(&mut self) -> Result<(), String> {
+        const NEEDLE: &[u8] = b"\"features\"";
+        let mut matched = 0usize;
+
+        loop {
+            let byte = self
+                .next_byte()?
+                .ok_or_else(|| "no \"features\" array found in GeoJSON".to_string())?;
 
-            let props = feature
-                .properties
-                .map(|p| serde_json::to_string(&p).unwrap_or_default())
-                .unwrap_or_else(|| "{}".to_string());
+            if matched == NEEDLE.len() {
+                match byte {
+                    b' ' | b'\t' | b'\n' | b'\r' => continue,
+                    b':' => {
+                        loop {
+                            let next = self.next_byte()?.ok_or_else(|| {
+                                "no \"features\" array found in GeoJSON".to_string()
+                            })?;
+                            match next {
+                                b' ' | b'\t' | b'\n' | b'\r' => continue,
+                                b'[' => return Ok(()),
+                                _ => {
+                                    // Not an array value, so this wasn't the
+                                    // real "features" key. Re-evaluate this
+                                    // byte as the possible start of another
+                                    // match instead of discarding it.
+                                    matched = if next == NEEDLE[0] { 1 } else { 0 };
+                                    break;
+                                }
+                            }
+                        }
+                        continue;
+                    }
+                    _ => {
+                        // No `:` separator right after the key, so this
+                        // wasn't the real "features" key either.
+                        matched = if byte == NEEDLE[0] { 1 } else { 0 };
+                        continue;
+                    }
+                }
+            }
 
-            parsed.push(ParsedFeature {
-                geometry: geom.to_string(),
-                properties: props,
-                geom_type: geom_type.to_string(),
-            });
+            matched = if byte == NEEDLE[matched] {
+                matched + 1
+            } else if byte == NEEDLE[0] {
+                1
+            } else {
+                0
+            };
         }
     }
 
-    Ok(parsed)
+    /// Read the raw text of the next feature object, or `None` once the
+    /// array is exhausted.
+    fn next_feature(&mut self) -> Result<Option<String>, String> {
+        if self.finished {
+            return Ok(None);
+        }
+        if !self.started {
+            self.seek_to_features_array()?;
+            self.started = true;
+        }
+
+        // Skip whitespace/commas until the start of an object or the
+        // closing `]` of the array.
+        loop {
+            let byte = self
+                .next_byte()?
+                .ok_or_else(|| "unexpected end of file inside features array".to_string())?;
+            match byte {
+                b' ' | b'\t' | b'\n' | b'\r' | b',' => continue,
+                b']' => {
+                    self.finished = true;
+                    return Ok(None);
+                }
+                b'{' => break,
+                other => {
+                    return Err(format!(
+                        "unexpected byte 0x{:02x} while scanning features array",
+                        other
+                    ))
+                }
+            }
+        }
+
+        // The opening `{` was already consumed above.
+        let mut bytes = vec![b'{'];
+        let mut depth = 1i32;
+        let mut in_string = false;
+        let mut escape = false;
+
+        while depth > 0 {
+            let byte = self
+                .next_byte()?
+                .ok_or_else(|| "unexpected end of file inside feature object".to_string())?;
+            bytes.push(byte);
+
+            if in_string {
+                if escape {
+                    escape = false;
+                } else if byte == b'\\' {
+                    escape = true;
+                } else if byte == b'"' {
+                    in_string = false;
+                }
+                continue;
+            }
+
+            match byte {
+                b'"' => in_string = true,
+                b'{' => depth += 1,
+                b'}' => depth -= 1,
+                _ => {}
+            }
+        }
+
+        String::from_utf8(bytes).map(Some).map_err(|e| e.to_string())
+    }
+}
+
+/// Reproject every coordinate in a GeoJSON geometry value into WGS84,
+/// recursing into `GeometryCollection` members.
+fn reproject_value(value: Value, source_srid: i32) -> Result<Value, String> {
+    Ok(match value {
+        Value::Point(p) => Value::Point(reproject_position(&p, source_srid)?),
+        Value::MultiPoint(ps) => Value::MultiPoint(reproject_ring(&ps, source_srid)?),
+        Value::LineString(ls) => Value::LineString(reproject_ring(&ls, source_srid)?),
+        Value::MultiLineString(mls) => Value::MultiLineString(
+            mls.iter()
+                .map(|ls| reproject_ring(ls, source_srid))
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::Polygon(poly) => Value::Polygon(
+            poly.iter()
+                .map(|ring| reproject_ring(ring, source_srid))
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::MultiPolygon(mp) => Value::MultiPolygon(
+            mp.iter()
+                .map(|poly| {
+                    poly.iter()
+                        .map(|ring| reproject_ring(ring, source_srid))
+                        .collect::<Result<_, _>>()
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+        Value::GeometryCollection(geoms) => Value::GeometryCollection(
+            geoms
+                .into_iter()
+                .map(|g| {
+                    Ok(geojson::Geometry {
+                        value: reproject_value(g.value, source_srid)?,
+                        ..g
+                    })
+                })
+                .collect::<Result<_, _>>()?,
+        ),
+    })
+}
+
+fn reproject_ring(ring: &[PointType], source_srid: i32) -> Result<Vec<PointType>, String> {
+    ring.iter()
+        .map(|p| reproject_position(p, source_srid))
+        .collect()
+}
+
+fn reproject_position(pos: &PointType, source_srid: i32) -> Result<PointType, String> {
+    let (lon, lat) = crs::to_wgs84(pos[0], pos[1], source_srid)?;
+    let mut reprojected = vec![lon, lat];
+    reprojected.extend_from_slice(&pos[2..]);
+    Ok(reprojected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    const SAMPLE_GEOJSON: &str = r#"{"type":"FeatureCollection","features":[{"type":"Feature","geometry":{"type":"Point","coordinates":[1.0,2.0]},"properties":{"name":"a"}},{"type":"Feature","geometry":{"type":"Point","coordinates":[3.0,4.0]},"properties":{"name":"b"}}]}"#;
+
+    #[test]
+    fn scanner_finds_top_level_features_key_despite_string_context() {
+        let mut scanner = FeatureArrayScanner::new(Cursor::new(SAMPLE_GEOJSON.as_bytes()));
+
+        let first = scanner.next_feature().unwrap().expect("first feature");
+        assert!(first.contains(r#""name":"a""#));
+
+        let second = scanner.next_feature().unwrap().expect("second feature");
+        assert!(second.contains(r#""name":"b""#));
+
+        assert!(scanner.next_feature().unwrap().is_none());
+    }
+
+    #[test]
+    fn scanner_ignores_features_key_that_is_not_the_top_level_array() {
+        let geojson = r#"{"properties":{"features":"river delta"},"items":[1,2,3],"features":[{"type":"Feature","geometry":null,"properties":{"name":"a"}}]}"#;
+
+        let mut scanner = FeatureArrayScanner::new(Cursor::new(geojson.as_bytes()));
+
+        let first = scanner.next_feature().unwrap().expect("the real feature");
+        assert!(first.contains(r#""name":"a""#));
+        assert!(scanner.next_feature().unwrap().is_none());
+    }
+
+    #[test]
+    fn stream_geojson_features_reads_all_features_in_chunks() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!(
+            "parser_test_{}_{}.geojson",
+            std::process::id(),
+            SAMPLE_GEOJSON.len()
+        ));
+        fs::write(&path, SAMPLE_GEOJSON).unwrap();
+
+        let batches = stream_geojson_features(path.to_str().unwrap(), 1, None).unwrap();
+        fs::remove_file(&path).ok();
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].features.len(), 1);
+        assert_eq!(batches[0].features[0].geom_type, "Point");
+        assert_eq!(batches[1].features.len(), 1);
+    }
 }
@@ -1,7 +1,12 @@
+use crate::crs;
+use crate::geometry::parser::{feature_to_parsed, FeatureBatch};
+use geo::Geometry;
 use pyo3::prelude::*;
 use rayon::prelude::*;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use wkt::TryFromWkt;
+
+const WGS84_SRID: i32 = 4326;
+const DEFAULT_DELIMITER: char = ',';
 
 #[pyclass]
 #[derive(Debug, Clone)]
@@ -11,59 +16,200 @@ pub struct CsvPointBatch {
 }
 
 #[pyfunction]
+#[pyo3(signature = (path, lon_col, lat_col, chunk_size, source_srid=None, delimiter=None))]
 pub fn stream_csv_points(
     path: &str,
     lon_col: &str,
     lat_col: &str,
     chunk_size: usize,
+    source_srid: Option<i32>,
+    delimiter: Option<char>,
 ) -> PyResult<Vec<CsvPointBatch>> {
-    let file = File::open(path)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
-    let reader = BufReader::new(file);
-    let mut lines = reader.lines();
-
-    let header = lines
-        .next()
-        .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>("Empty CSV file"))?
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?;
-
-    let columns: Vec<&str> = header.split(',').collect();
-    let lon_idx = columns.iter().position(|&c| c.trim() == lon_col).ok_or_else(|| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Column '{}' not found", lon_col))
-    })?;
-    let lat_idx = columns.iter().position(|&c| c.trim() == lat_col).ok_or_else(|| {
-        PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Column '{}' not found", lat_col))
-    })?;
-
-    let all_lines: Vec<String> = lines.filter_map(|l| l.ok()).collect();
-
-    let records: Vec<(f64, f64, String)> = all_lines
+    let mut reader = csv_reader(path, delimiter)?;
+    let headers = reader
+        .headers()
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?
+        .clone();
+
+    let lon_idx = column_index(&headers, lon_col)?;
+    let lat_idx = column_index(&headers, lat_col)?;
+    let srid = source_srid.unwrap_or(WGS84_SRID);
+
+    // Fail fast on an unsupported SRID rather than silently dropping every
+    // row once the parallel parse below hits it.
+    if srid != WGS84_SRID {
+        crs::to_wgs84(0.0, 0.0, srid)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+    }
+
+    let records: Vec<csv::StringRecord> = reader
+        .records()
+        .filter_map(|r| r.ok())
+        .collect();
+
+    let points: Vec<(f64, f64, String)> = records
         .par_iter()
-        .filter_map(|line| {
-            let fields: Vec<&str> = line.split(',').collect();
-            if fields.len() <= lon_idx.max(lat_idx) {
-                return None;
-            }
-            let lon: f64 = fields[lon_idx].trim().parse().ok()?;
-            let lat: f64 = fields[lat_idx].trim().parse().ok()?;
+        .filter_map(|record| {
+            let raw_lon: f64 = record.get(lon_idx)?.trim().parse().ok()?;
+            let raw_lat: f64 = record.get(lat_idx)?.trim().parse().ok()?;
+            let (lon, lat) = if srid == WGS84_SRID {
+                (raw_lon, raw_lat)
+            } else {
+                crs::to_wgs84(raw_lon, raw_lat, srid).ok()?
+            };
 
             let mut props = serde_json::Map::new();
-            for (i, col) in columns.iter().enumerate() {
-                if i != lon_idx && i != lat_idx && i < fields.len() {
-                    props.insert(
-                        col.trim().to_string(),
-                        serde_json::Value::String(fields[i].trim().to_string()),
-                    );
+            for (i, col) in headers.iter().enumerate() {
+                if i == lon_idx || i == lat_idx {
+                    continue;
+                }
+                if let Some(value) = record.get(i) {
+                    props.insert(col.trim().to_string(), serde_json::Value::String(value.trim().to_string()));
                 }
             }
+
             Some((lon, lat, serde_json::to_string(&props).unwrap_or_default()))
         })
         .collect();
 
-    let batches: Vec<CsvPointBatch> = records
-        .chunks(chunk_size)
+    Ok(points
+        .chunks(chunk_size.max(1))
         .map(|chunk| CsvPointBatch { points: chunk.to_vec() })
-        .collect();
+        .collect())
+}
+
+/// Stream features from a CSV where a single column holds a WKT geometry
+/// (`POINT`, `POLYGON`, etc.) instead of separate lon/lat columns, e.g.
+/// exports from GIS tools and spatial databases. Every other column becomes
+/// a string property, mirroring `stream_csv_points`.
+#[pyfunction]
+#[pyo3(signature = (path, geometry_col, chunk_size, source_srid=None, delimiter=None))]
+pub fn stream_csv_wkt_features(
+    path: &str,
+    geometry_col: &str,
+    chunk_size: usize,
+    source_srid: Option<i32>,
+    delimiter: Option<char>,
+) -> PyResult<Vec<FeatureBatch>> {
+    let mut reader = csv_reader(path, delimiter)?;
+    let headers = reader
+        .headers()
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))?
+        .clone();
+
+    let geom_idx = column_index(&headers, geometry_col)?;
+    let srid = source_srid.unwrap_or(WGS84_SRID);
+
+    // Fail fast on an unsupported SRID rather than only surfacing it once
+    // the first row is reprojected (or never, if the file has no rows).
+    if srid != WGS84_SRID {
+        crs::to_wgs84(0.0, 0.0, srid)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?;
+    }
+
+    let mut batches = Vec::new();
+    let mut current = Vec::with_capacity(chunk_size.max(1));
+
+    for result in reader.records() {
+        let record = result.map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e.to_string()))?;
+        let Some(wkt_text) = record.get(geom_idx) else {
+            continue;
+        };
+
+        let geometry = Geometry::<f64>::try_from_wkt_str(wkt_text.trim()).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "invalid WKT '{}' in column '{}': {}",
+                wkt_text, geometry_col, e
+            ))
+        })?;
+
+        let mut props = serde_json::Map::new();
+        for (i, col) in headers.iter().enumerate() {
+            if i == geom_idx {
+                continue;
+            }
+            if let Some(value) = record.get(i) {
+                props.insert(col.trim().to_string(), serde_json::Value::String(value.trim().to_string()));
+            }
+        }
+
+        let feature = geojson::Feature {
+            geometry: Some(geojson::Geometry::new(geojson::Value::from(&geometry))),
+            properties: Some(serde_json::Value::Object(props)),
+            id: None,
+            bbox: None,
+            foreign_members: None,
+        };
+
+        if let Some(parsed) = feature_to_parsed(feature, srid)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyValueError, _>(e))?
+        {
+            current.push(parsed);
+            if current.len() == chunk_size.max(1) {
+                batches.push(FeatureBatch {
+                    features: std::mem::take(&mut current),
+                });
+            }
+        }
+    }
+
+    if !current.is_empty() {
+        batches.push(FeatureBatch { features: current });
+    }
 
     Ok(batches)
 }
+
+/// Build an RFC 4180-aware CSV reader (quoting/escaping handled by the
+/// `csv` crate rather than a raw `split(delimiter)`) over `path`.
+fn csv_reader(path: &str, delimiter: Option<char>) -> PyResult<csv::Reader<std::fs::File>> {
+    let delim = delimiter.unwrap_or(DEFAULT_DELIMITER);
+    if !delim.is_ascii() {
+        return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "delimiter '{}' must be a single ASCII character",
+            delim
+        )));
+    }
+
+    csv::ReaderBuilder::new()
+        .delimiter(delim as u8)
+        .from_path(path)
+        .map_err(|e| PyErr::new::<pyo3::exceptions::PyIOError, _>(e.to_string()))
+}
+
+fn column_index(headers: &csv::StringRecord, name: &str) -> PyResult<usize> {
+    headers
+        .iter()
+        .position(|c| c.trim() == name)
+        .ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!("Column '{}' not found", name))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_temp_csv(name: &str, content: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("csv_test_{}_{}", std::process::id(), name));
+        std::fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn stream_csv_points_keeps_comma_embedded_in_a_quoted_field() {
+        let path = write_temp_csv(
+            "points.csv",
+            "lon,lat,name\n1.0,2.0,\"Springfield, IL\"\n",
+        );
+
+        let batches = stream_csv_points(path.to_str().unwrap(), "lon", "lat", 10, None, None).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert_eq!(batches.len(), 1);
+        let (lon, lat, props) = &batches[0].points[0];
+        assert_eq!((*lon, *lat), (1.0, 2.0));
+        let props: serde_json::Value = serde_json::from_str(props).unwrap();
+        assert_eq!(props["name"], "Springfield, IL");
+    }
+}
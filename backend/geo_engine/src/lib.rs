@@ -1,10 +1,14 @@
 use pyo3::prelude::*;
 
+mod crs;
 mod geometry;
 mod import;
 
-use geometry::{parse_geojson_file, parse_geojson_string, parser::ParsedFeature};
-use import::{stream_csv_points, CsvPointBatch};
+use geometry::{
+    parse_geojson_file, parse_geojson_string,
+    parser::{stream_geojson_features, FeatureBatch, ParsedFeature},
+};
+use import::{stream_csv_points, stream_csv_wkt_features, CsvPointBatch};
 
 #[pyfunction]
 fn version() -> String {
@@ -17,7 +21,10 @@ fn geo_engine(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(parse_geojson_file, m)?)?;
     m.add_function(wrap_pyfunction!(parse_geojson_string, m)?)?;
     m.add_function(wrap_pyfunction!(stream_csv_points, m)?)?;
+    m.add_function(wrap_pyfunction!(stream_csv_wkt_features, m)?)?;
+    m.add_function(wrap_pyfunction!(stream_geojson_features, m)?)?;
     m.add_class::<ParsedFeature>()?;
     m.add_class::<CsvPointBatch>()?;
+    m.add_class::<FeatureBatch>()?;
     Ok(())
 }
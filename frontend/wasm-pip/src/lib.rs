@@ -3,12 +3,18 @@
 //! Provides client-side point-in-polygon queries using R-Tree spatial indexing
 //! for high-performance geospatial operations in the browser.
 
-use geo::{Point, Polygon};
-use rstar::RTree;
+use geo::{Area, BoundingRect, Contains, Point, Polygon};
+use num_traits::Zero;
+use rstar::{PointDistance, RTree, RTreeObject, RTreeNum, AABB};
 use serde::{Deserialize, Serialize};
 use std::cell::RefCell;
 use wasm_bindgen::prelude::*;
 
+#[cfg(all(feature = "postgis", not(target_arch = "wasm32")))]
+mod datasource;
+#[cfg(all(feature = "postgis", not(target_arch = "wasm32")))]
+pub use datasource::{load_polygons, PostgisSource};
+
 // Panic hook for better error messages in WASM
 #[wasm_bindgen(start)]
 pub fn init() {
@@ -16,27 +22,40 @@ pub fn init() {
     console_error_panic_hook::set_once();
 }
 
+/// Coordinate scalar usable in the spatial index: `f64` (the default, kept
+/// for backward compatibility) or `f32`, which roughly halves the R-Tree's
+/// memory footprint for boundary sets where ~7 significant digits suffice.
+pub trait CoordFloat:
+    geo::GeoFloat + RTreeNum + Serialize + for<'de> Deserialize<'de> + 'static
+{
+}
+
+impl<T> CoordFloat for T where
+    T: geo::GeoFloat + RTreeNum + Serialize + for<'de> Deserialize<'de> + 'static
+{
+}
+
 /// Coordinate pair for polygon vertices
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct Coordinate {
-    pub x: f64,
-    pub y: f64,
+pub struct Coordinate<T = f64> {
+    pub x: T,
+    pub y: T,
 }
 
 /// Polygon with metadata for spatial queries
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct PolygonData {
+pub struct PolygonData<T = f64> {
     pub id: String,
-    pub rings: Vec<Coordinate>,
+    pub rings: Vec<Coordinate<T>>,
     #[serde(default)]
     pub properties: Option<serde_json::Value>,
 }
 
 /// Point query request
 #[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct PointQuery {
-    pub x: f64,
-    pub y: f64,
+pub struct PointQuery<T = f64> {
+    pub x: T,
+    pub y: T,
 }
 
 /// Point query response
@@ -46,6 +65,11 @@ pub struct PointQueryResult {
     pub polygon_id: Option<String>,
     pub query_time_us: u64,
     pub candidates_checked: usize,
+    /// Present only when the query requested `resolve_all`: every matching
+    /// polygon id, ascending by area, describing the full containment
+    /// hierarchy (e.g. city, then county, then country).
+    #[serde(default)]
+    pub matches: Option<Vec<String>>,
 }
 
 /// Batch query result
@@ -78,218 +102,210 @@ impl WasmPoint {
     }
 }
 
-/// Thread-local spatial index using R-Tree
-#[wasm_bindgen]
-pub struct SpatialIndex {
-    tree: RefCell<RTree<Polygon<f64>>>,
-    polygon_ids: RefCell<Vec<String>>,
-    polygon_properties: RefCell<Vec<Option<serde_json::Value>>>,
+/// A polygon paired with its id and properties, stored directly as the
+/// R-Tree's element type so the tree's own ordering is the single source of
+/// truth. Previously the id/properties lived in parallel `Vec`s indexed
+/// alongside `tree.iter()`, which silently desynced once `bulk_load`
+/// reordered the tree.
+struct IndexedPolygon<T: CoordFloat> {
+    poly: Polygon<T>,
+    id: String,
+    props: Option<serde_json::Value>,
+    /// Precomputed at insert time so ranking overlapping matches by
+    /// specificity doesn't recompute the area on every query.
+    area: T,
 }
 
-#[wasm_bindgen]
-impl SpatialIndex {
-    /// Create a new empty spatial index
-    #[wasm_bindgen(constructor)]
-    pub fn new() -> SpatialIndex {
-        console_error_panic_hook::set_once();
+impl<T: CoordFloat> RTreeObject for IndexedPolygon<T> {
+    type Envelope = AABB<[T; 2]>;
 
-        SpatialIndex {
-            tree: RefCell::new(RTree::new()),
-            polygon_ids: RefCell::new(Vec::new()),
-            polygon_properties: RefCell::new(Vec::new()),
+    fn envelope(&self) -> Self::Envelope {
+        match self.poly.bounding_rect() {
+            Some(rect) => {
+                AABB::from_corners([rect.min().x, rect.min().y], [rect.max().x, rect.max().y])
+            }
+            // Empty exterior ring (no bounding rect); fall back to a
+            // zero-area envelope at the origin rather than panicking.
+            None => AABB::from_point([T::zero(), T::zero()]),
         }
     }
+}
 
-    /// Clear all polygons from the index
-    #[wasm_bindgen]
-    pub fn clear(&self) {
-        let mut tree = self.tree.borrow_mut();
-        let mut ids = self.polygon_ids.borrow_mut();
-        let mut props = self.polygon_properties.borrow_mut();
+impl<T: CoordFloat> PointDistance for IndexedPolygon<T> {
+    fn distance_2(&self, point: &[T; 2]) -> T {
+        self.envelope().distance_2(point)
+    }
 
-        *tree = RTree::new();
-        ids.clear();
-        props.clear();
+    fn contains_point(&self, point: &[T; 2]) -> bool {
+        self.poly.contains(&Point::new(point[0], point[1]))
     }
+}
+
+/// Precision-generic core of the spatial index. `wasm_bindgen` can't export
+/// a generic type directly, so this holds all the real logic and the
+/// `#[wasm_bindgen]` structs below (`SpatialIndex` for `f64`, `SpatialIndexF32`
+/// for `f32`) are thin per-precision wrappers around it.
+struct SpatialIndexCore<T: CoordFloat> {
+    tree: RefCell<RTree<IndexedPolygon<T>>>,
+}
 
-    /// Get the number of polygons in the index
-    #[wasm_bindgen]
-    pub fn len(&self) -> usize {
-        self.polygon_ids.borrow().len()
+impl<T: CoordFloat> SpatialIndexCore<T> {
+    fn new() -> Self {
+        SpatialIndexCore {
+            tree: RefCell::new(RTree::new()),
+        }
     }
 
-    /// Check if the index is empty
-    #[wasm_bindgen]
-    pub fn is_empty(&self) -> bool {
-        self.polygon_ids.borrow().is_empty()
+    fn clear(&self) {
+        *self.tree.borrow_mut() = RTree::new();
     }
 
-    /// Add a single polygon to the index
-    #[wasm_bindgen]
-    pub fn add_polygon(&self, id: String, rings: JsValue) -> Result<(), JsValue> {
-        let coords: Vec<Coordinate> = serde_wasm_bindgen::from_value(rings)
-            .map_err(|e| JsValue::from_str(&format!("Invalid coordinates: {}", e)))?;
+    fn len(&self) -> usize {
+        self.tree.borrow().size()
+    }
 
-        let polygon = create_polygon(&coords)?;
+    fn is_empty(&self) -> bool {
+        self.tree.borrow().size() == 0
+    }
 
-        let mut tree = self.tree.borrow_mut();
-        let mut ids = self.polygon_ids.borrow_mut();
-        let mut props = self.polygon_properties.borrow_mut();
+    fn add_polygon(&self, id: String, coords: &[Coordinate<T>]) -> Result<(), String> {
+        let poly = create_polygon(coords)?;
+        let area = poly.unsigned_area();
 
-        tree.insert(polygon);
-        ids.push(id);
-        props.push(None);
+        self.tree.borrow_mut().insert(IndexedPolygon {
+            poly,
+            id,
+            props: None,
+            area,
+        });
 
         Ok(())
     }
 
-    /// Add multiple polygons to the index
-    #[wasm_bindgen]
-    pub fn add_polygons(&self, polygons: JsValue) -> Result<(), JsValue> {
-        let polys: Vec<PolygonData> = serde_wasm_bindgen::from_value(polygons)
-            .map_err(|e| JsValue::from_str(&format!("Invalid polygon data: {}", e)))?;
-
-        let mut tree = self.tree.borrow_mut();
-        let mut ids = self.polygon_ids.borrow_mut();
-        let mut props = self.polygon_properties.borrow_mut();
-
-        let mut new_polygons = Vec::with_capacity(polys.len());
-        let mut new_ids = Vec::with_capacity(polys.len());
-        let mut new_props = Vec::with_capacity(polys.len());
-
+    fn add_polygons(&self, polys: Vec<PolygonData<T>>) -> Result<(), String> {
+        let mut new_entries = Vec::with_capacity(polys.len());
         for poly in polys {
-            let polygon = create_polygon(&poly.rings)?;
-            new_polygons.push(polygon);
-            new_ids.push(poly.id);
-            new_props.push(poly.properties);
+            let geom = create_polygon(&poly.rings)?;
+            let area = geom.unsigned_area();
+            new_entries.push(IndexedPolygon {
+                poly: geom,
+                id: poly.id,
+                props: poly.properties,
+                area,
+            });
         }
 
         // Bulk load for optimal R-Tree construction
-        let mut all_polygons = tree.iter().cloned().collect::<Vec<_>>();
-        all_polygons.extend(new_polygons);
-        *tree = RTree::bulk_load(all_polygons);
-
-        ids.extend(new_ids);
-        props.extend(new_props);
+        let mut tree = self.tree.borrow_mut();
+        let existing = std::mem::replace(&mut *tree, RTree::new());
+        let mut all_entries: Vec<IndexedPolygon<T>> = existing.into_iter().collect();
+        all_entries.extend(new_entries);
+        *tree = RTree::bulk_load(all_entries);
 
         Ok(())
     }
 
-    /// Query a single point
-    #[wasm_bindgen]
-    pub fn query(&self, x: f64, y: f64) -> JsValue {
-        let start = web_time::Instant::now();
+    /// When multiple polygons contain the point (e.g. nested administrative
+    /// boundaries), the smallest-area match is returned as `polygon_id`
+    /// since it is the most specific region. Pass `resolve_all: true` to
+    /// instead get every containing polygon id in `matches`, ascending by
+    /// area, to reconstruct the full containment hierarchy.
+    fn query(&self, x: T, y: T, resolve_all: bool) -> PointQueryResult {
         let point = Point::new(x, y);
 
         let tree = self.tree.borrow();
-        let ids = self.polygon_ids.borrow();
-        let props = self.polygon_properties.borrow();
+        let env = AABB::from_point([x, y]);
 
-        // Use R-Tree to find candidate polygons
+        // Narrow to polygons whose bounding box intersects the query point
+        // before running the exact containment test, instead of checking
+        // every polygon in the index.
         let mut candidates_checked = 0;
-        let mut found_id = None;
+        let mut matches: Vec<(&str, T)> = Vec::new();
 
-        for (idx, polygon) in tree.iter().enumerate() {
+        for candidate in tree.locate_in_envelope_intersecting(&env) {
             candidates_checked += 1;
-            if polygon.contains(&point) {
-                found_id = Some(ids[idx].clone());
-                break;
+            if candidate.poly.contains(&point) {
+                matches.push((&candidate.id, candidate.area));
             }
         }
 
-        let result = PointQueryResult {
-            found: found_id.is_some(),
-            polygon_id: found_id,
-            query_time_us: start.elapsed().as_micros() as u64,
-            candidates_checked,
-        };
+        matches.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
 
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        PointQueryResult {
+            found: !matches.is_empty(),
+            polygon_id: matches.first().map(|(id, _)| id.to_string()),
+            query_time_us: 0,
+            candidates_checked,
+            matches: resolve_all
+                .then(|| matches.iter().map(|(id, _)| id.to_string()).collect()),
+        }
     }
 
-    /// Query multiple points
-    #[wasm_bindgen]
-    pub fn query_batch(&self, points: JsValue) -> JsValue {
-        let points: Vec<PointQuery> = serde_wasm_bindgen::from_value(points)
-            .unwrap_or_else(|_| Vec::new());
-
-        let start = web_time::Instant::now();
+    fn query_batch(&self, points: &[PointQuery<T>]) -> Vec<PointQueryResult> {
         let tree = self.tree.borrow();
-        let ids = self.polygon_ids.borrow();
 
-        let results: Vec<PointQueryResult> = points
+        points
             .iter()
             .map(|p| {
                 let point = Point::new(p.x, p.y);
+                let env = AABB::from_point([p.x, p.y]);
                 let mut candidates_checked = 0;
-                let mut found_id = None;
+                let mut best: Option<(&str, T)> = None;
 
-                for (idx, polygon) in tree.iter().enumerate() {
+                for candidate in tree.locate_in_envelope_intersecting(&env) {
                     candidates_checked += 1;
-                    if polygon.contains(&point) {
-                        found_id = Some(ids[idx].clone());
-                        break;
+                    if candidate.poly.contains(&point)
+                        && best.map_or(true, |(_, area)| candidate.area < area)
+                    {
+                        best = Some((&candidate.id, candidate.area));
                     }
                 }
 
                 PointQueryResult {
-                    found: found_id.is_some(),
-                    polygon_id: found_id,
+                    found: best.is_some(),
+                    polygon_id: best.map(|(id, _)| id.to_string()),
                     query_time_us: 0, // Individual timing not available in batch
                     candidates_checked,
+                    matches: None,
                 }
             })
-            .collect();
-
-        let batch_result = BatchQueryResult {
-            results,
-            total_time_us: start.elapsed().as_micros() as u64,
-        };
-
-        serde_wasm_bindgen::to_value(&batch_result).unwrap_or(JsValue::NULL)
+            .collect()
     }
 
-    /// Get statistics about the index
-    #[wasm_bindgen]
-    pub fn stats(&self) -> JsValue {
-        let ids = self.polygon_ids.borrow();
-        let props = self.polygon_properties.borrow();
+    fn stats(&self) -> IndexStats {
+        let count = self.tree.borrow().size();
 
-        // Rough estimate of memory usage
-        let estimated_bytes = ids.len() * 64 + props.len() * 128;
+        // Rough estimate of memory usage; narrower coordinates halve the
+        // coordinate share of the per-polygon cost (the fixed 128 bytes stay).
+        let coord_bytes = std::mem::size_of::<T>() * 2;
+        let estimated_bytes = count * coord_bytes * 4 + count * 128;
 
-        let stats = IndexStats {
-            polygon_count: ids.len(),
+        IndexStats {
+            polygon_count: count,
             estimated_size_bytes: estimated_bytes,
-        };
-
-        serde_wasm_bindgen::to_value(&stats).unwrap_or(JsValue::NULL)
+        }
     }
 
-    /// Export the index as JSON (for caching)
-    #[wasm_bindgen]
-    pub fn export(&self) -> JsValue {
-        let tree = self.tree.borrow();
-        let ids = self.polygon_ids.borrow();
-        let props = self.polygon_properties.borrow();
-
-        let polygons: Vec<PolygonData> = tree
+    fn export(&self) -> Vec<PolygonData<T>> {
+        self.tree
+            .borrow()
             .iter()
-            .zip(ids.iter())
-            .zip(props.iter())
-            .map(|((polygon, id), prop)| {
-                let exterior: Vec<Coordinate> = polygon
+            .map(|entry| {
+                let exterior: Vec<Coordinate<T>> = entry
+                    .poly
                     .exterior()
                     .0
                     .iter()
                     .map(|c| Coordinate { x: c.x, y: c.y })
                     .collect();
 
-                let interiors: Vec<Vec<Coordinate>> = polygon
+                let interiors: Vec<Vec<Coordinate<T>>> = entry
+                    .poly
                     .interiors()
                     .iter()
                     .map(|ring| {
-                        ring.0.iter()
+                        ring.0
+                            .iter()
                             .map(|c| Coordinate { x: c.x, y: c.y })
                             .collect()
                     })
@@ -299,25 +315,142 @@ impl SpatialIndex {
                 rings.extend(interiors);
 
                 PolygonData {
-                    id: id.clone(),
-                    rings,
-                    properties: prop.clone(),
+                    id: entry.id.clone(),
+                    rings: rings.into_iter().flatten().collect(),
+                    properties: entry.props.clone(),
                 }
             })
-            .collect();
-
-        serde_wasm_bindgen::to_value(&polygons).unwrap_or(JsValue::NULL)
+            .collect()
     }
+}
+
+/// Generates a `#[wasm_bindgen]` newtype wrapper around `SpatialIndexCore<$coord>`
+/// together with its full method surface. `SpatialIndex` (`f64`) and
+/// `SpatialIndexF32` (`f32`) are otherwise identical except for the scalar
+/// type, so both are generated from this one definition instead of being
+/// kept in sync by hand.
+macro_rules! spatial_index_wrapper {
+    ($name:ident, $coord:ty, $doc:expr) => {
+        #[doc = $doc]
+        #[wasm_bindgen]
+        pub struct $name(SpatialIndexCore<$coord>);
+
+        #[wasm_bindgen]
+        impl $name {
+            /// Create a new empty spatial index
+            #[wasm_bindgen(constructor)]
+            pub fn new() -> $name {
+                console_error_panic_hook::set_once();
+                $name(SpatialIndexCore::new())
+            }
 
-    /// Import polygons from JSON (for loading cached index)
-    #[wasm_bindgen]
-    pub fn import_data(&self, data: JsValue) -> Result<(), JsValue> {
-        self.add_polygons(data)
+            /// Clear all polygons from the index
+            #[wasm_bindgen]
+            pub fn clear(&self) {
+                self.0.clear();
+            }
+
+            /// Get the number of polygons in the index
+            #[wasm_bindgen]
+            pub fn len(&self) -> usize {
+                self.0.len()
+            }
+
+            /// Check if the index is empty
+            #[wasm_bindgen]
+            pub fn is_empty(&self) -> bool {
+                self.0.is_empty()
+            }
+
+            /// Add a single polygon to the index
+            #[wasm_bindgen]
+            pub fn add_polygon(&self, id: String, rings: JsValue) -> Result<(), JsValue> {
+                let coords: Vec<Coordinate<$coord>> = serde_wasm_bindgen::from_value(rings)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid coordinates: {}", e)))?;
+                self.0
+                    .add_polygon(id, &coords)
+                    .map_err(|e| JsValue::from_str(&e))
+            }
+
+            /// Add multiple polygons to the index
+            #[wasm_bindgen]
+            pub fn add_polygons(&self, polygons: JsValue) -> Result<(), JsValue> {
+                let polys: Vec<PolygonData<$coord>> = serde_wasm_bindgen::from_value(polygons)
+                    .map_err(|e| JsValue::from_str(&format!("Invalid polygon data: {}", e)))?;
+                self.0.add_polygons(polys).map_err(|e| JsValue::from_str(&e))
+            }
+
+            /// Query a single point.
+            #[wasm_bindgen]
+            pub fn query(&self, x: $coord, y: $coord, resolve_all: bool) -> JsValue {
+                let start = web_time::Instant::now();
+                let mut result = self.0.query(x, y, resolve_all);
+                result.query_time_us = start.elapsed().as_micros() as u64;
+                serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+            }
+
+            /// Query multiple points
+            #[wasm_bindgen]
+            pub fn query_batch(&self, points: JsValue) -> JsValue {
+                let points: Vec<PointQuery<$coord>> =
+                    serde_wasm_bindgen::from_value(points).unwrap_or_else(|_| Vec::new());
+
+                let start = web_time::Instant::now();
+                let results = self.0.query_batch(&points);
+
+                let batch_result = BatchQueryResult {
+                    results,
+                    total_time_us: start.elapsed().as_micros() as u64,
+                };
+
+                serde_wasm_bindgen::to_value(&batch_result).unwrap_or(JsValue::NULL)
+            }
+
+            /// Get statistics about the index
+            #[wasm_bindgen]
+            pub fn stats(&self) -> JsValue {
+                serde_wasm_bindgen::to_value(&self.0.stats()).unwrap_or(JsValue::NULL)
+            }
+
+            /// Export the index as JSON (for caching)
+            #[wasm_bindgen]
+            pub fn export(&self) -> JsValue {
+                serde_wasm_bindgen::to_value(&self.0.export()).unwrap_or(JsValue::NULL)
+            }
+
+            /// Import polygons from JSON (for loading cached index)
+            #[wasm_bindgen]
+            pub fn import_data(&self, data: JsValue) -> Result<(), JsValue> {
+                self.add_polygons(data)
+            }
+        }
+    };
+}
+
+spatial_index_wrapper!(
+    SpatialIndex,
+    f64,
+    "Thread-local `f64` spatial index using R-Tree (the default precision)."
+);
+
+/// Native-only bulk load of what the PostGIS datasource's `load_polygons`
+/// returns, refreshing the index without round-tripping through
+/// `JsValue`/GeoJSON.
+#[cfg(all(feature = "postgis", not(target_arch = "wasm32")))]
+impl SpatialIndex {
+    pub fn load_polygon_data(&self, polys: Vec<PolygonData<f64>>) -> Result<(), String> {
+        self.0.add_polygons(polys)
     }
 }
 
+spatial_index_wrapper!(
+    SpatialIndexF32,
+    f32,
+    "Thread-local `f32` spatial index using R-Tree, roughly halving the\nR-Tree's memory footprint versus [`SpatialIndex`] for boundary sets\nwhere ~7 significant digits of precision suffice."
+);
+
 /// Create a Geo polygon from coordinate rings
-fn create_polygon(coords: &[Coordinate]) -> Result<Polygon<f64>, String> {
+fn create_polygon<T: CoordFloat>(coords: &[Coordinate<T>]) -> Result<Polygon<T>, String> {
     if coords.is_empty() {
         return Err("Empty coordinate ring".to_string());
     }
@@ -345,3 +478,84 @@ pub fn initialize_panic_hook() {
     #[cfg(feature = "console_error_panic_hook")]
     console_error_panic_hook::set_once();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A closed square ring, generic over the index's coordinate precision.
+    fn square<T: CoordFloat>(min_x: f64, min_y: f64, max_x: f64, max_y: f64) -> Vec<Coordinate<T>> {
+        let corner = |x: f64, y: f64| Coordinate {
+            x: T::from(x).unwrap(),
+            y: T::from(y).unwrap(),
+        };
+        vec![
+            corner(min_x, min_y),
+            corner(max_x, min_y),
+            corner(max_x, max_y),
+            corner(min_x, max_y),
+            corner(min_x, min_y),
+        ]
+    }
+
+    #[test]
+    fn candidates_checked_excludes_polygons_whose_envelope_misses_the_point() {
+        let index = SpatialIndexCore::<f64>::new();
+        index
+            .add_polygon("near".to_string(), &square(0.0, 0.0, 1.0, 1.0))
+            .unwrap();
+        for i in 0..10 {
+            let offset = 100.0 * (i as f64 + 1.0);
+            index
+                .add_polygon(
+                    format!("far-{i}"),
+                    &square(offset, offset, offset + 1.0, offset + 1.0),
+                )
+                .unwrap();
+        }
+
+        let result = index.query(0.5, 0.5, false);
+
+        assert!(result.found);
+        assert_eq!(result.polygon_id.as_deref(), Some("near"));
+        assert!(result.candidates_checked < index.len());
+    }
+
+    #[test]
+    fn query_resolves_smallest_area_and_resolve_all_lists_every_match_by_area() {
+        let index = SpatialIndexCore::<f64>::new();
+        index
+            .add_polygon("country".to_string(), &square(0.0, 0.0, 10.0, 10.0))
+            .unwrap();
+        index
+            .add_polygon("city".to_string(), &square(4.0, 4.0, 6.0, 6.0))
+            .unwrap();
+
+        let result = index.query(5.0, 5.0, false);
+        assert_eq!(result.polygon_id.as_deref(), Some("city"));
+        assert!(result.matches.is_none());
+
+        let all = index.query(5.0, 5.0, true);
+        assert_eq!(
+            all.matches,
+            Some(vec!["city".to_string(), "country".to_string()])
+        );
+    }
+
+    #[test]
+    fn f32_core_round_trips_through_export_and_add_polygons() {
+        let index = SpatialIndexCore::<f32>::new();
+        index
+            .add_polygon("square".to_string(), &square::<f32>(0.0, 0.0, 2.0, 2.0))
+            .unwrap();
+
+        let exported = index.export();
+        assert_eq!(exported.len(), 1);
+
+        let reloaded = SpatialIndexCore::<f32>::new();
+        reloaded.add_polygons(exported).unwrap();
+
+        let result = reloaded.query(1.0, 1.0, false);
+        assert_eq!(result.polygon_id.as_deref(), Some("square"));
+    }
+}